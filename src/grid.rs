@@ -1,5 +1,6 @@
 use std::{
-    cell::RefCell,
+    cell::{Cell, RefCell},
+    collections::HashMap,
 };
 use crate::{
     core::{
@@ -38,6 +39,11 @@ pub struct Column {
     desired_width: f32,
     actual_width: f32,
     x: f32,
+    /// Relative weight of this column among other stretch-sized columns.
+    /// Has no effect unless `size_mode` is `SizeMode::Stretch`.
+    weight: f32,
+    min_size: Option<f32>,
+    max_size: Option<f32>,
 }
 
 impl Column {
@@ -47,6 +53,9 @@ impl Column {
             desired_width,
             actual_width: 0.0,
             x: 0.0,
+            weight: 1.0,
+            min_size: None,
+            max_size: None,
         }
     }
 
@@ -56,6 +65,9 @@ impl Column {
             desired_width,
             actual_width: 0.0,
             x: 0.0,
+            weight: 1.0,
+            min_size: None,
+            max_size: None,
         }
     }
 
@@ -65,6 +77,21 @@ impl Column {
             desired_width: 0.0,
             actual_width: 0.0,
             x: 0.0,
+            weight: 1.0,
+            min_size: None,
+            max_size: None,
+        }
+    }
+
+    pub fn stretch_weighted(weight: f32) -> Self {
+        Self {
+            size_mode: SizeMode::Stretch,
+            desired_width: 0.0,
+            actual_width: 0.0,
+            x: 0.0,
+            weight,
+            min_size: None,
+            max_size: None,
         }
     }
 
@@ -74,8 +101,21 @@ impl Column {
             desired_width: 0.0,
             actual_width: 0.0,
             x: 0.0,
+            weight: 1.0,
+            min_size: None,
+            max_size: None,
         }
     }
+
+    pub fn with_min(mut self, min_size: f32) -> Self {
+        self.min_size = Some(min_size);
+        self
+    }
+
+    pub fn with_max(mut self, max_size: f32) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -84,6 +124,11 @@ pub struct Row {
     desired_height: f32,
     actual_height: f32,
     y: f32,
+    /// Relative weight of this row among other stretch-sized rows.
+    /// Has no effect unless `size_mode` is `SizeMode::Stretch`.
+    weight: f32,
+    min_size: Option<f32>,
+    max_size: Option<f32>,
 }
 
 impl Row {
@@ -93,6 +138,9 @@ impl Row {
             desired_height,
             actual_height: 0.0,
             y: 0.0,
+            weight: 1.0,
+            min_size: None,
+            max_size: None,
         }
     }
 
@@ -102,6 +150,9 @@ impl Row {
             desired_height,
             actual_height: 0.0,
             y: 0.0,
+            weight: 1.0,
+            min_size: None,
+            max_size: None,
         }
     }
 
@@ -111,6 +162,21 @@ impl Row {
             desired_height: 0.0,
             actual_height: 0.0,
             y: 0.0,
+            weight: 1.0,
+            min_size: None,
+            max_size: None,
+        }
+    }
+
+    pub fn stretch_weighted(weight: f32) -> Self {
+        Self {
+            size_mode: SizeMode::Stretch,
+            desired_height: 0.0,
+            actual_height: 0.0,
+            y: 0.0,
+            weight,
+            min_size: None,
+            max_size: None,
         }
     }
 
@@ -120,8 +186,34 @@ impl Row {
             desired_height: 0.0,
             actual_height: 0.0,
             y: 0.0,
+            weight: 1.0,
+            min_size: None,
+            max_size: None,
         }
     }
+
+    pub fn with_min(mut self, min_size: f32) -> Self {
+        self.min_size = Some(min_size);
+        self
+    }
+
+    pub fn with_max(mut self, max_size: f32) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+}
+
+/// Snapshot of a completed `measure_override` pass, kept around so that a following pass with
+/// unchanged inputs can skip re-solving the track layout entirely.
+struct LayoutCache<M: 'static, C: 'static + Control<M, C>> {
+    available_size: Vec2,
+    revision: u64,
+    columns: Vec<Column>,
+    rows: Vec<Row>,
+    desired_size: Vec2,
+    /// Per-child `(desired_size, visibility)` as of this pass. Both feed `Auto` track sizing,
+    /// so either one drifting invalidates the cache.
+    child_layout_state: HashMap<Handle<UINode<M, C>>, (Vec2, bool)>,
 }
 
 /// Automatically arranges children by rows and columns
@@ -131,6 +223,15 @@ pub struct Grid<M: 'static, C: 'static + Control<M, C>> {
     columns: RefCell<Vec<Column>>,
     draw_border: bool,
     border_thickness: f32,
+    /// Gutter inserted between horizontally adjacent columns.
+    horizontal_spacing: f32,
+    /// Gutter inserted between vertically adjacent rows.
+    vertical_spacing: f32,
+    /// Uniform margin kept between the grid's bounds and the content area of its tracks.
+    padding: f32,
+    /// Bumped whenever rows/columns are structurally mutated, invalidating `layout_cache`.
+    revision: Cell<u64>,
+    layout_cache: RefCell<Option<LayoutCache<M, C>>>,
 }
 
 impl<M: 'static, C: 'static + Control<M, C>> Deref for Grid<M, C> {
@@ -155,6 +256,11 @@ impl<M, C: 'static + Control<M, C>> Control<M, C> for Grid<M, C> {
             columns: self.columns.clone(),
             draw_border: self.draw_border,
             border_thickness: self.border_thickness,
+            horizontal_spacing: self.horizontal_spacing,
+            vertical_spacing: self.vertical_spacing,
+            padding: self.padding,
+            revision: Cell::new(self.revision.get()),
+            layout_cache: RefCell::new(None),
         })
     }
 
@@ -164,6 +270,31 @@ impl<M, C: 'static + Control<M, C>> Control<M, C> for Grid<M, C> {
             return self.widget.measure_override(ui, available_size);
         }
 
+        // If nothing structural changed since the last pass, the offered size is the same, the
+        // child set is the same, and every child's own desired size and visibility still match
+        // what they were when the cache was built, reuse the solved track layout instead of
+        // re-running the full measure pass. Any drift in either (e.g. its text changed, or it
+        // was hidden/shown) can change an `Auto` track's size, so it forces a full re-solve
+        // rather than being patched in against stale track sizes.
+        if let Some(cache) = self.layout_cache.borrow().as_ref() {
+            let children = self.widget.children();
+            let children_unchanged = children.len() == cache.child_layout_state.len()
+                && children.iter().all(|h| {
+                    let child = ui.nodes.borrow(*h);
+                    cache.child_layout_state.get(h) == Some(&(child.desired_size(), child.visibility()))
+                });
+
+            if cache.available_size == available_size
+                && cache.revision == self.revision.get()
+                && children_unchanged
+            {
+                *self.columns.borrow_mut() = cache.columns.clone();
+                *self.rows.borrow_mut() = cache.rows.clone();
+
+                return cache.desired_size;
+            }
+        }
+
         let mut desired_size = Vec2::ZERO;
         // Step 1. Measure every children with relaxed constraints (size of grid).
         for child_handle in self.widget.children() {
@@ -177,6 +308,9 @@ impl<M, C: 'static + Control<M, C>> Control<M, C> for Grid<M, C> {
         self.fit_stretch_sized_columns(ui, available_size, preset_width);
         self.fit_stretch_sized_rows(ui, available_size, preset_height);
 
+        self.clamp_stretch_sized_columns();
+        self.clamp_stretch_sized_rows();
+
         self.arrange_rows();
         self.arrange_columns();
 
@@ -184,9 +318,13 @@ impl<M, C: 'static + Control<M, C>> Control<M, C> for Grid<M, C> {
         for child_handle in self.widget.children() {
             let size_for_child = {
                 let child = ui.nodes.borrow(*child_handle);
+                let columns = self.columns.borrow();
+                let rows = self.rows.borrow();
+                let column_end = (child.column() + child.column_span()).min(columns.len());
+                let row_end = (child.row() + child.row_span()).min(rows.len());
                 Vec2 {
-                    x: self.columns.borrow()[child.column()].actual_width,
-                    y: self.rows.borrow()[child.row()].actual_height,
+                    x: self.spanned_width(&columns[child.column()..column_end]),
+                    y: self.spanned_height(&rows[child.row()..row_end]),
                 }
             };
             ui.node(*child_handle).measure(ui, size_for_child);
@@ -196,9 +334,28 @@ impl<M, C: 'static + Control<M, C>> Control<M, C> for Grid<M, C> {
         for column in self.columns.borrow().iter() {
             desired_size.x += column.actual_width;
         }
+        desired_size.x += self.horizontal_spacing * self.columns.borrow().len().saturating_sub(1) as f32;
+        desired_size.x += self.padding * 2.0;
         for row in self.rows.borrow().iter() {
             desired_size.y += row.actual_height;
         }
+        desired_size.y += self.vertical_spacing * self.rows.borrow().len().saturating_sub(1) as f32;
+        desired_size.y += self.padding * 2.0;
+
+        let child_layout_state = self.widget.children().iter()
+            .map(|h| {
+                let child = ui.nodes.borrow(*h);
+                (*h, (child.desired_size(), child.visibility()))
+            })
+            .collect();
+        *self.layout_cache.borrow_mut() = Some(LayoutCache {
+            available_size,
+            revision: self.revision.get(),
+            columns: self.columns.borrow().clone(),
+            rows: self.rows.borrow().clone(),
+            desired_size,
+            child_layout_state,
+        });
 
         desired_size
     }
@@ -216,14 +373,15 @@ impl<M, C: 'static + Control<M, C>> Control<M, C> for Grid<M, C> {
             let mut final_rect = None;
 
             let child = ui.nodes.borrow(*child_handle);
-            if let Some(column) = self.columns.borrow().get(child.column()) {
-                if let Some(row) = self.rows.borrow().get(child.row()) {
-                    final_rect = Some(Rect::new(
-                        column.x,
-                        row.y,
-                        column.actual_width,
-                        row.actual_height,
-                    ));
+            let columns = self.columns.borrow();
+            let rows = self.rows.borrow();
+            if let Some(column) = columns.get(child.column()) {
+                if let Some(row) = rows.get(child.row()) {
+                    let column_end = (child.column() + child.column_span()).min(columns.len());
+                    let row_end = (child.row() + child.row_span()).min(rows.len());
+                    let width = self.spanned_width(&columns[child.column()..column_end]);
+                    let height = self.spanned_height(&rows[child.row()..row_end]);
+                    final_rect = Some(Rect::new(column.x, row.y, width, height));
                 }
             }
 
@@ -249,14 +407,18 @@ impl<M, C: 'static + Control<M, C>> Control<M, C> for Grid<M, C> {
             drawing_context.push_line(right_bottom, left_bottom, self.border_thickness);
             drawing_context.push_line(left_bottom, left_top, self.border_thickness);
 
-            for column in self.columns.borrow().iter() {
-                let a = Vec2::new(bounds.x + column.x, bounds.y);
-                let b = Vec2::new(bounds.x + column.x, bounds.y + bounds.h);
+            // Column/row separators land in the center of the gutter between adjacent tracks;
+            // the outermost edges are already covered by the border drawn above.
+            for column in self.columns.borrow().iter().skip(1) {
+                let line_x = column.x - self.horizontal_spacing / 2.0;
+                let a = Vec2::new(bounds.x + line_x, bounds.y);
+                let b = Vec2::new(bounds.x + line_x, bounds.y + bounds.h);
                 drawing_context.push_line(a, b, self.border_thickness);
             }
-            for row in self.rows.borrow().iter() {
-                let a = Vec2::new(bounds.x, bounds.y + row.y);
-                let b = Vec2::new(bounds.x + bounds.w, bounds.y + row.y);
+            for row in self.rows.borrow().iter().skip(1) {
+                let line_y = row.y - self.vertical_spacing / 2.0;
+                let a = Vec2::new(bounds.x, bounds.y + line_y);
+                let b = Vec2::new(bounds.x + bounds.w, bounds.y + line_y);
                 drawing_context.push_line(a, b, self.border_thickness);
             }
 
@@ -275,6 +437,9 @@ pub struct GridBuilder<M: 'static, C: 'static + Control<M, C>> {
     columns: Vec<Column>,
     draw_border: bool,
     border_thickness: f32,
+    horizontal_spacing: f32,
+    vertical_spacing: f32,
+    padding: f32,
 }
 
 impl<M, C: 'static + Control<M, C>> GridBuilder<M, C> {
@@ -285,6 +450,9 @@ impl<M, C: 'static + Control<M, C>> GridBuilder<M, C> {
             columns: Vec::new(),
             draw_border: false,
             border_thickness: 1.0,
+            horizontal_spacing: 0.0,
+            vertical_spacing: 0.0,
+            padding: 0.0,
         }
     }
 
@@ -318,6 +486,21 @@ impl<M, C: 'static + Control<M, C>> GridBuilder<M, C> {
         self
     }
 
+    pub fn with_horizontal_spacing(mut self, value: f32) -> Self {
+        self.horizontal_spacing = value;
+        self
+    }
+
+    pub fn with_vertical_spacing(mut self, value: f32) -> Self {
+        self.vertical_spacing = value;
+        self
+    }
+
+    pub fn with_padding(mut self, value: f32) -> Self {
+        self.padding = value;
+        self
+    }
+
     pub fn build(self, ui: &mut UserInterface<M, C>) -> Handle<UINode<M, C>> {
         let handle = ui.add_node(UINode::Grid(Grid {
             widget: self.widget_builder.build(),
@@ -325,6 +508,11 @@ impl<M, C: 'static + Control<M, C>> GridBuilder<M, C> {
             columns: RefCell::new(self.columns),
             draw_border: self.draw_border,
             border_thickness: self.border_thickness,
+            horizontal_spacing: self.horizontal_spacing,
+            vertical_spacing: self.vertical_spacing,
+            padding: self.padding,
+            revision: Cell::new(0),
+            layout_cache: RefCell::new(None),
         }));
 
         ui.flush_messages();
@@ -341,79 +529,157 @@ impl<M, C: 'static + Control<M, C>> Grid<M, C> {
             columns: Default::default(),
             draw_border: false,
             border_thickness: 1.0,
+            horizontal_spacing: 0.0,
+            vertical_spacing: 0.0,
+            padding: 0.0,
+            revision: Cell::new(0),
+            layout_cache: RefCell::new(None),
         }
     }
 
+    /// Invalidates the cached track layout, forcing the next `measure_override` to re-solve it.
+    fn bump_revision(&self) {
+        self.revision.set(self.revision.get() + 1);
+    }
+
     pub fn add_row(&mut self, row: Row) -> &mut Self {
         self.rows.borrow_mut().push(row);
+        self.bump_revision();
         self
     }
 
     pub fn add_column(&mut self, column: Column) -> &mut Self {
         self.columns.borrow_mut().push(column);
+        self.bump_revision();
         self
     }
 
     pub fn clear_columns(&mut self) {
         self.columns.borrow_mut().clear();
+        self.bump_revision();
     }
 
     pub fn clear_rows(&mut self) {
         self.rows.borrow_mut().clear();
+        self.bump_revision();
     }
 
     pub fn set_columns(&mut self, columns: Vec<Column>) {
         self.columns = RefCell::new(columns);
+        self.bump_revision();
     }
 
     pub fn set_rows(&mut self, rows: Vec<Row>) {
         self.rows = RefCell::new(rows);
+        self.bump_revision();
     }
 
     fn calculate_preset_width(&self, ui: &UserInterface<M, C>) -> f32 {
-        let mut preset_width = 0.0;
-
-        // Calculate size of strict-sized and auto-sized columns.
+        // Calculate size of strict-sized and auto-sized columns from non-spanning children first.
         for (i, col) in self.columns.borrow_mut().iter_mut().enumerate() {
             if col.size_mode == SizeMode::Strict {
                 col.actual_width = col.desired_width;
-                preset_width += col.actual_width;
             } else if col.size_mode == SizeMode::Auto {
                 col.actual_width = col.desired_width;
                 for child_handle in self.widget.children() {
                     let child = ui.nodes.borrow(*child_handle);
-                    if child.column() == i && child.visibility() && child.desired_size().x > col.actual_width {
+                    if child.column() == i && child.column_span() == 1 && child.visibility() && child.desired_size().x > col.actual_width {
                         col.actual_width = child.desired_size().x;
                     }
                 }
-                preset_width += col.actual_width;
             }
         }
 
-        preset_width
+        // Spanning children only inflate the auto-sized columns they cover, and only by the
+        // shortfall the already-sized columns in their span cannot accommodate. A span that
+        // covers a stretch column is left alone here: stretch columns are not sized for this pass
+        // yet (they hold 0 or the previous pass's width until `fit_stretch_sized_columns` runs
+        // later and gives them the remaining space), so their width here cannot be counted as
+        // covering the span.
+        for child_handle in self.widget.children() {
+            let child = ui.nodes.borrow(*child_handle);
+            if child.column_span() > 1 && child.visibility() {
+                let start = child.column();
+                let end = (start + child.column_span()).min(self.columns.borrow().len());
+                let spans_stretch = self.columns.borrow()[start..end].iter().any(|c| c.size_mode == SizeMode::Stretch);
+                if spans_stretch {
+                    continue;
+                }
+                let covered: f32 = self.columns.borrow()[start..end].iter().map(|c| c.actual_width).sum();
+                let shortfall = child.desired_size().x - covered;
+                if shortfall > 0.0 {
+                    let mut columns = self.columns.borrow_mut();
+                    let auto_count = columns[start..end].iter().filter(|c| c.size_mode == SizeMode::Auto).count();
+                    if auto_count > 0 {
+                        let extra = shortfall / auto_count as f32;
+                        for col in columns[start..end].iter_mut() {
+                            if col.size_mode == SizeMode::Auto {
+                                col.actual_width += extra;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        self.columns.borrow().iter()
+            .filter(|col| col.size_mode == SizeMode::Strict || col.size_mode == SizeMode::Auto)
+            .map(|col| col.actual_width)
+            .sum()
     }
 
     fn calculate_preset_height(&self, ui: &UserInterface<M, C>) -> f32 {
-        let mut preset_height = 0.0;
-
-        // Calculate size of strict-sized and auto-sized rows.
+        // Calculate size of strict-sized and auto-sized rows from non-spanning children first.
         for (i, row) in self.rows.borrow_mut().iter_mut().enumerate() {
             if row.size_mode == SizeMode::Strict {
                 row.actual_height = row.desired_height;
-                preset_height += row.actual_height;
             } else if row.size_mode == SizeMode::Auto {
                 row.actual_height = row.desired_height;
                 for child_handle in self.widget.children() {
                     let child = ui.nodes.borrow(*child_handle);
-                    if child.row() == i && child.visibility() && child.desired_size().y > row.actual_height {
+                    if child.row() == i && child.row_span() == 1 && child.visibility() && child.desired_size().y > row.actual_height {
                         row.actual_height = child.desired_size().y;
                     }
                 }
-                preset_height += row.actual_height;
             }
         }
 
-        preset_height
+        // Spanning children only inflate the auto-sized rows they cover, and only by the
+        // shortfall the already-sized rows in their span cannot accommodate. A span that covers
+        // a stretch row is left alone here: stretch rows are not sized for this pass yet (they
+        // hold 0 or the previous pass's height until `fit_stretch_sized_rows` runs later and
+        // gives them the remaining space), so their height here cannot be counted as covering
+        // the span.
+        for child_handle in self.widget.children() {
+            let child = ui.nodes.borrow(*child_handle);
+            if child.row_span() > 1 && child.visibility() {
+                let start = child.row();
+                let end = (start + child.row_span()).min(self.rows.borrow().len());
+                let spans_stretch = self.rows.borrow()[start..end].iter().any(|r| r.size_mode == SizeMode::Stretch);
+                if spans_stretch {
+                    continue;
+                }
+                let covered: f32 = self.rows.borrow()[start..end].iter().map(|r| r.actual_height).sum();
+                let shortfall = child.desired_size().y - covered;
+                if shortfall > 0.0 {
+                    let mut rows = self.rows.borrow_mut();
+                    let auto_count = rows[start..end].iter().filter(|r| r.size_mode == SizeMode::Auto).count();
+                    if auto_count > 0 {
+                        let extra = shortfall / auto_count as f32;
+                        for row in rows[start..end].iter_mut() {
+                            if row.size_mode == SizeMode::Auto {
+                                row.actual_height += extra;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        self.rows.borrow().iter()
+            .filter(|row| row.size_mode == SizeMode::Strict || row.size_mode == SizeMode::Auto)
+            .map(|row| row.actual_height)
+            .sum()
     }
 
     fn fit_stretch_sized_columns(&self, ui: &UserInterface<M, C>, available_size: Vec2, preset_width: f32) {
@@ -428,28 +694,27 @@ impl<M, C: 'static + Control<M, C>> Grid<M, C> {
                 }
             }
         } else {
-            rest_width = available_size.x - preset_width;
+            let total_gutter = self.horizontal_spacing * self.columns.borrow().len().saturating_sub(1) as f32;
+            rest_width = available_size.x - preset_width - total_gutter - self.padding * 2.0;
         }
 
-        // count columns first
-        let mut stretch_sized_columns = 0;
+        // sum weights of stretch columns first
+        let mut total_weight = 0.0;
         for column in self.columns.borrow().iter() {
             if column.size_mode == SizeMode::Stretch {
-                stretch_sized_columns += 1;
+                total_weight += column.weight;
             }
         }
-        if stretch_sized_columns > 0 {
-            let width_per_col = rest_width / stretch_sized_columns as f32;
+        if total_weight > 0.0 {
             for column in self.columns.borrow_mut().iter_mut() {
                 if column.size_mode == SizeMode::Stretch {
-                    column.actual_width = width_per_col;
+                    column.actual_width = rest_width * (column.weight / total_weight);
                 }
             }
         }
     }
 
     fn fit_stretch_sized_rows(&self, ui: &UserInterface<M, C>, available_size: Vec2, preset_height: f32) {
-        let mut stretch_sized_rows = 0;
         let mut rest_height = 0.0;
         if available_size.y.is_infinite() {
             for child_handle in self.widget.children() {
@@ -461,40 +726,161 @@ impl<M, C: 'static + Control<M, C>> Grid<M, C> {
                 }
             }
         } else {
-            rest_height = available_size.y - preset_height;
+            let total_gutter = self.vertical_spacing * self.rows.borrow().len().saturating_sub(1) as f32;
+            rest_height = available_size.y - preset_height - total_gutter - self.padding * 2.0;
         }
-        // count rows first
+        // sum weights of stretch rows first
+        let mut total_weight = 0.0;
         for row in self.rows.borrow().iter() {
             if row.size_mode == SizeMode::Stretch {
-                stretch_sized_rows += 1;
+                total_weight += row.weight;
             }
         }
-        if stretch_sized_rows > 0 {
-            let height_per_row = rest_height / stretch_sized_rows as f32;
+        if total_weight > 0.0 {
             for row in self.rows.borrow_mut().iter_mut() {
                 if row.size_mode == SizeMode::Stretch {
-                    row.actual_height = height_per_row;
+                    row.actual_height = rest_height * (row.weight / total_weight);
+                }
+            }
+        }
+    }
+
+    /// Clamps stretch-sized columns to their `min_size`/`max_size` bounds, re-spreading any
+    /// space a clamped column refuses over the remaining flexible stretch columns. Iterates
+    /// until no column violates its bounds or no flexible column is left to absorb the change.
+    fn clamp_stretch_sized_columns(&self) {
+        let mut locked = vec![false; self.columns.borrow().len()];
+        loop {
+            let mut columns = self.columns.borrow_mut();
+            let mut remainder = 0.0;
+            let mut violated = false;
+
+            for (i, col) in columns.iter_mut().enumerate() {
+                if col.size_mode != SizeMode::Stretch || locked[i] {
+                    continue;
+                }
+                if let Some(min_size) = col.min_size {
+                    if col.actual_width < min_size {
+                        remainder -= min_size - col.actual_width;
+                        col.actual_width = min_size;
+                        locked[i] = true;
+                        violated = true;
+                    }
+                }
+                if let Some(max_size) = col.max_size {
+                    if col.actual_width > max_size {
+                        remainder += col.actual_width - max_size;
+                        col.actual_width = max_size;
+                        locked[i] = true;
+                        violated = true;
+                    }
+                }
+            }
+
+            if !violated {
+                break;
+            }
+
+            let flexible_weight: f32 = columns.iter().enumerate()
+                .filter(|(i, col)| col.size_mode == SizeMode::Stretch && !locked[*i])
+                .map(|(_, col)| col.weight)
+                .sum();
+
+            if flexible_weight <= 0.0 {
+                break;
+            }
+
+            for (i, col) in columns.iter_mut().enumerate() {
+                if col.size_mode == SizeMode::Stretch && !locked[i] {
+                    col.actual_width += remainder * (col.weight / flexible_weight);
+                }
+            }
+        }
+    }
+
+    /// Clamps stretch-sized rows to their `min_size`/`max_size` bounds, re-spreading any
+    /// space a clamped row refuses over the remaining flexible stretch rows. Iterates until
+    /// no row violates its bounds or no flexible row is left to absorb the change.
+    fn clamp_stretch_sized_rows(&self) {
+        let mut locked = vec![false; self.rows.borrow().len()];
+        loop {
+            let mut rows = self.rows.borrow_mut();
+            let mut remainder = 0.0;
+            let mut violated = false;
+
+            for (i, row) in rows.iter_mut().enumerate() {
+                if row.size_mode != SizeMode::Stretch || locked[i] {
+                    continue;
+                }
+                if let Some(min_size) = row.min_size {
+                    if row.actual_height < min_size {
+                        remainder -= min_size - row.actual_height;
+                        row.actual_height = min_size;
+                        locked[i] = true;
+                        violated = true;
+                    }
+                }
+                if let Some(max_size) = row.max_size {
+                    if row.actual_height > max_size {
+                        remainder += row.actual_height - max_size;
+                        row.actual_height = max_size;
+                        locked[i] = true;
+                        violated = true;
+                    }
+                }
+            }
+
+            if !violated {
+                break;
+            }
+
+            let flexible_weight: f32 = rows.iter().enumerate()
+                .filter(|(i, row)| row.size_mode == SizeMode::Stretch && !locked[*i])
+                .map(|(_, row)| row.weight)
+                .sum();
+
+            if flexible_weight <= 0.0 {
+                break;
+            }
+
+            for (i, row) in rows.iter_mut().enumerate() {
+                if row.size_mode == SizeMode::Stretch && !locked[i] {
+                    row.actual_height += remainder * (row.weight / flexible_weight);
                 }
             }
         }
     }
 
     fn arrange_rows(&self) {
-        let mut y = 0.0;
+        let mut y = self.padding;
         for row in self.rows.borrow_mut().iter_mut() {
             row.y = y;
-            y += row.actual_height;
+            y += row.actual_height + self.vertical_spacing;
         }
     }
 
     fn arrange_columns(&self) {
-        let mut x = 0.0;
+        let mut x = self.padding;
         for column in self.columns.borrow_mut().iter_mut() {
             column.x = x;
-            x += column.actual_width;
+            x += column.actual_width + self.horizontal_spacing;
         }
     }
 
+    /// Width spanned by `columns`, from the left edge of the first column to the right edge of
+    /// the last, including the gutters between them.
+    fn spanned_width(&self, columns: &[Column]) -> f32 {
+        let width: f32 = columns.iter().map(|c| c.actual_width).sum();
+        width + self.horizontal_spacing * columns.len().saturating_sub(1) as f32
+    }
+
+    /// Height spanned by `rows`, from the top edge of the first row to the bottom edge of the
+    /// last, including the gutters between them.
+    fn spanned_height(&self, rows: &[Row]) -> f32 {
+        let height: f32 = rows.iter().map(|r| r.actual_height).sum();
+        height + self.vertical_spacing * rows.len().saturating_sub(1) as f32
+    }
+
     pub fn set_draw_border(&mut self, value: bool) -> &mut Self {
         self.draw_border = value;
         self
@@ -512,4 +898,34 @@ impl<M, C: 'static + Control<M, C>> Grid<M, C> {
     pub fn border_thickness(&self) -> f32 {
         self.border_thickness
     }
+
+    pub fn set_horizontal_spacing(&mut self, value: f32) -> &mut Self {
+        self.horizontal_spacing = value;
+        self.bump_revision();
+        self
+    }
+
+    pub fn horizontal_spacing(&self) -> f32 {
+        self.horizontal_spacing
+    }
+
+    pub fn set_vertical_spacing(&mut self, value: f32) -> &mut Self {
+        self.vertical_spacing = value;
+        self.bump_revision();
+        self
+    }
+
+    pub fn vertical_spacing(&self) -> f32 {
+        self.vertical_spacing
+    }
+
+    pub fn set_padding(&mut self, value: f32) -> &mut Self {
+        self.padding = value;
+        self.bump_revision();
+        self
+    }
+
+    pub fn padding(&self) -> f32 {
+        self.padding
+    }
 }